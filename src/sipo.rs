@@ -1,14 +1,25 @@
 //! Serial-in parallel-out shift register
 
 use core::cell::RefCell;
-use core::mem::{self, MaybeUninit};
 
+use spin::Mutex;
+
+use crate::hal::blocking::spi::Write as SpiWrite;
 use crate::hal::digital::v2::OutputPin;
 
 trait ShiftRegisterInternal {
     fn update(&self, index: usize, command: bool) -> Result<(), ()>;
 }
 
+/// Order in which `output_state` bits are clocked out onto the data pin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShiftDirection {
+    /// Bit 0 of `output_state` is clocked out last (the current default ordering)
+    Msb,
+    /// Bit 0 of `output_state` is clocked out first
+    Lsb,
+}
+
 /// Output pin of the shift register
 pub struct ShiftRegisterPin<'a> {
     shift_register: &'a dyn ShiftRegisterInternal,
@@ -38,55 +49,248 @@ impl OutputPin for ShiftRegisterPin<'_> {
     }
 }
 
-macro_rules! shift_register_builder {
+/// Serial-in parallel-out shift register with `N` outputs
+pub struct ShiftRegister<Pin1, Pin2, Pin3, const N: usize = 8>
+where
+    Pin1: OutputPin,
+    Pin2: OutputPin,
+    Pin3: OutputPin,
+{
+    clock: RefCell<Pin1>,
+    latch: RefCell<Pin2>,
+    data: RefCell<Pin3>,
+    output_state: RefCell<[bool; N]>,
+    inverted: bool,
+    shift_direction: ShiftDirection,
+    deferred: RefCell<bool>,
+}
+
+impl<Pin1, Pin2, Pin3, const N: usize> ShiftRegisterInternal for ShiftRegister<Pin1, Pin2, Pin3, N>
+where
+    Pin1: OutputPin,
+    Pin2: OutputPin,
+    Pin3: OutputPin,
+{
+    /// Sets the value of the shift register output at `index` to value `command`
+    fn update(&self, index: usize, command: bool) -> Result<(), ()> {
+        self.output_state.borrow_mut()[index] = command;
+        if *self.deferred.borrow() {
+            return Ok(());
+        }
+        self.flush()
+    }
+}
+
+impl<Pin1, Pin2, Pin3, const N: usize> ShiftRegister<Pin1, Pin2, Pin3, N>
+where
+    Pin1: OutputPin,
+    Pin2: OutputPin,
+    Pin3: OutputPin,
+{
+    /// Shifts the current `output_state` out onto the data/clock pins and pulses
+    /// the latch, making it visible on the register outputs
+    fn flush(&self) -> Result<(), ()> {
+        let output_state = self.output_state.borrow();
+        if self.inverted {
+            self.latch.borrow_mut().set_high().map_err(|_e| ())?;
+        } else {
+            self.latch.borrow_mut().set_low().map_err(|_e| ())?;
+        }
+
+        for i in 1..=output_state.len() {
+            let index = match self.shift_direction {
+                ShiftDirection::Msb => output_state.len() - i,
+                ShiftDirection::Lsb => i - 1,
+            };
+            if output_state[index] {
+                if self.inverted {
+                    self.data.borrow_mut().set_low().map_err(|_e| ())?;
+                } else {
+                    self.data.borrow_mut().set_high().map_err(|_e| ())?;
+                }
+            } else {
+                if self.inverted {
+                    self.data.borrow_mut().set_high().map_err(|_e| ())?;
+                } else {
+                    self.data.borrow_mut().set_low().map_err(|_e| ())?;
+                }
+            }
+            self.clock.borrow_mut().set_high().map_err(|_e| ())?;
+            self.clock.borrow_mut().set_low().map_err(|_e| ())?;
+        }
+
+        if self.inverted {
+            self.latch.borrow_mut().set_low().map_err(|_e| ())?;
+        } else {
+            self.latch.borrow_mut().set_high().map_err(|_e| ())?;
+        }
+        Ok(())
+    }
+
+    /// Creates a new SIPO shift register from clock, latch, and data output pins
+    pub fn new(clock: Pin1, latch: Pin2, data: Pin3) -> Self {
+        ShiftRegister {
+            clock: RefCell::new(clock),
+            latch: RefCell::new(latch),
+            data: RefCell::new(data),
+            output_state: RefCell::new([false; N]),
+            inverted: false,
+            shift_direction: ShiftDirection::Msb,
+            deferred: RefCell::new(false),
+        }
+    }
+
+    /// Inverts the latch output pin. This depends on which shift register is used.
+    pub fn inverted(mut self, state: bool) -> Self {
+        self.inverted = state;
+        self
+    }
+
+    /// Sets the order in which `output_state` bits are clocked out. Defaults to
+    /// [`ShiftDirection::Msb`], matching the previous fixed bit ordering.
+    pub fn shift_direction(mut self, direction: ShiftDirection) -> Self {
+        self.shift_direction = direction;
+        self
+    }
+
+    /// Starts a batch of writes: until [`Self::commit`] is called, pin writes only
+    /// update the internal `output_state` buffer and do not touch the clock, latch,
+    /// or data pins
+    pub fn begin(&self) {
+        *self.deferred.borrow_mut() = true;
+    }
+
+    /// Ends a batch started with [`Self::begin`], performing exactly one shift-out
+    /// and latch pulse for every write recorded since
+    pub fn commit(&self) -> Result<(), ()> {
+        *self.deferred.borrow_mut() = false;
+        self.flush()
+    }
+
+    /// Runs `f` with the register's decomposed pins in batch mode, then commits all
+    /// of its writes as a single shift-out and latch pulse. This avoids the O(N)
+    /// clock cycles per pin that the immediate-flush default pays when many pins
+    /// change at once.
+    pub fn batch(&self, f: impl FnOnce(&mut [ShiftRegisterPin])) -> Result<(), ()> {
+        self.begin();
+        let mut pins = self.decompose();
+        f(&mut pins);
+        self.commit()
+    }
+
+    /// Get embedded-hal output pins to control the shift register outputs
+    pub fn decompose(&self) -> [ShiftRegisterPin; N] {
+        core::array::from_fn(|index| ShiftRegisterPin::new(self, index))
+    }
+
+    /// Consume the shift register and return the original clock, latch, and data output pins
+    pub fn release(self) -> (Pin1, Pin2, Pin3) {
+        let Self {
+            clock,
+            latch,
+            data,
+            output_state: _,
+            inverted: _,
+            shift_direction: _,
+            deferred: _,
+        } = self;
+        (clock.into_inner(), latch.into_inner(), data.into_inner())
+    }
+}
+
+/// 8 output serial-in parallel-out shift register
+pub type ShiftRegister8<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 8>;
+/// 16 output serial-in parallel-out shift register
+pub type ShiftRegister16<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 16>;
+/// 24 output serial-in parallel-out shift register
+pub type ShiftRegister24<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 24>;
+/// 32 output serial-in parallel-out shift register
+pub type ShiftRegister32<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 32>;
+/// 40 output serial-in parallel-out shift register
+pub type ShiftRegister40<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 40>;
+/// 48 output serial-in parallel-out shift register
+pub type ShiftRegister48<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 48>;
+/// 56 output serial-in parallel-out shift register
+pub type ShiftRegister56<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 56>;
+/// 64 output serial-in parallel-out shift register
+pub type ShiftRegister64<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 64>;
+/// 72 output serial-in parallel-out shift register
+pub type ShiftRegister72<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 72>;
+/// 80 output serial-in parallel-out shift register
+pub type ShiftRegister80<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 80>;
+/// 88 output serial-in parallel-out shift register
+pub type ShiftRegister88<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 88>;
+/// 96 output serial-in parallel-out shift register
+pub type ShiftRegister96<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 96>;
+/// 104 output serial-in parallel-out shift register
+pub type ShiftRegister104<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 104>;
+/// 112 output serial-in parallel-out shift register
+pub type ShiftRegister112<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 112>;
+/// 120 output serial-in parallel-out shift register
+pub type ShiftRegister120<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 120>;
+/// 128 output serial-in parallel-out shift register
+pub type ShiftRegister128<Pin1, Pin2, Pin3> = ShiftRegister<Pin1, Pin2, Pin3, 128>;
+
+macro_rules! shift_register_spi_builder {
     ($name: ident, $size: expr) => {
-        /// Serial-in parallel-out shift register
-        pub struct $name<Pin1, Pin2, Pin3>
+        /// Serial-in parallel-out shift register whose data/clock lines are driven by
+        /// hardware SPI (MOSI/SCK) instead of bit-banged GPIO
+        pub struct $name<Spi, Pin2>
         where
-            Pin1: OutputPin,
+            Spi: SpiWrite<u8>,
             Pin2: OutputPin,
-            Pin3: OutputPin,
         {
-            clock: RefCell<Pin1>,
+            spi: RefCell<Spi>,
             latch: RefCell<Pin2>,
-            data: RefCell<Pin3>,
             output_state: RefCell<[bool; $size]>,
             inverted: bool,
+            shift_direction: ShiftDirection,
+            deferred: RefCell<bool>,
         }
 
-        impl<Pin1, Pin2, Pin3> ShiftRegisterInternal for $name<Pin1, Pin2, Pin3>
+        impl<Spi, Pin2> ShiftRegisterInternal for $name<Spi, Pin2>
         where
-            Pin1: OutputPin,
+            Spi: SpiWrite<u8>,
             Pin2: OutputPin,
-            Pin3: OutputPin,
         {
             /// Sets the value of the shift register output at `index` to value `command`
             fn update(&self, index: usize, command: bool) -> Result<(), ()> {
                 self.output_state.borrow_mut()[index] = command;
+                if *self.deferred.borrow() {
+                    return Ok(());
+                }
+                self.flush()
+            }
+        }
+
+        impl<Spi, Pin2> $name<Spi, Pin2>
+        where
+            Spi: SpiWrite<u8>,
+            Pin2: OutputPin,
+        {
+            /// Packs the current `output_state` into bytes and writes them out in a single
+            /// `spi.write`, then pulses the latch, making them visible on the register
+            /// outputs
+            fn flush(&self) -> Result<(), ()> {
                 let output_state = self.output_state.borrow();
+                let mut bytes = [0u8; ($size + 7) / 8];
+                for i in 1..=output_state.len() {
+                    let index = match self.shift_direction {
+                        ShiftDirection::Msb => output_state.len() - i,
+                        ShiftDirection::Lsb => i - 1,
+                    };
+                    if output_state[index] != self.inverted {
+                        bytes[(i - 1) / 8] |= 0x80 >> ((i - 1) % 8);
+                    }
+                }
+
                 if self.inverted {
                     self.latch.borrow_mut().set_high().map_err(|_e| ())?;
                 } else {
                     self.latch.borrow_mut().set_low().map_err(|_e| ())?;
                 }
 
-                for i in 1..=output_state.len() {
-                    if output_state[output_state.len() - i] {
-                        if self.inverted {
-                            self.data.borrow_mut().set_low().map_err(|_e| ())?;
-                        } else {
-                            self.data.borrow_mut().set_high().map_err(|_e| ())?;
-                        }
-                    } else {
-                        if self.inverted {
-                            self.data.borrow_mut().set_high().map_err(|_e| ())?;
-                        } else {
-                            self.data.borrow_mut().set_low().map_err(|_e| ())?;
-                        }
-                    }
-                    self.clock.borrow_mut().set_high().map_err(|_e| ())?;
-                    self.clock.borrow_mut().set_low().map_err(|_e| ())?;
-                }
+                self.spi.borrow_mut().write(&bytes).map_err(|_e| ())?;
 
                 if self.inverted {
                     self.latch.borrow_mut().set_low().map_err(|_e| ())?;
@@ -95,22 +299,17 @@ macro_rules! shift_register_builder {
                 }
                 Ok(())
             }
-        }
 
-        impl<Pin1, Pin2, Pin3> $name<Pin1, Pin2, Pin3>
-        where
-            Pin1: OutputPin,
-            Pin2: OutputPin,
-            Pin3: OutputPin,
-        {
-            /// Creates a new SIPO shift register from clock, latch, and data output pins
-            pub fn new(clock: Pin1, latch: Pin2, data: Pin3) -> Self {
+            /// Creates a new SIPO shift register driven by an SPI peripheral, with a
+            /// separate latch output pin
+            pub fn new_spi(spi: Spi, latch: Pin2) -> Self {
                 $name {
-                    clock: RefCell::new(clock),
+                    spi: RefCell::new(spi),
                     latch: RefCell::new(latch),
-                    data: RefCell::new(data),
                     output_state: RefCell::new([false; $size]),
                     inverted: false,
+                    shift_direction: ShiftDirection::Msb,
+                    deferred: RefCell::new(false),
                 }
             }
 
@@ -120,56 +319,422 @@ macro_rules! shift_register_builder {
                 self
             }
 
+            /// Sets the order in which `output_state` bits are clocked out. Defaults to
+            /// [`ShiftDirection::Msb`], matching the previous fixed bit ordering.
+            pub fn shift_direction(mut self, direction: ShiftDirection) -> Self {
+                self.shift_direction = direction;
+                self
+            }
+
+            /// Starts a batch of writes: until [`Self::commit`] is called, pin writes only
+            /// update the internal `output_state` buffer and do not touch the SPI peripheral
+            /// or latch pin
+            pub fn begin(&self) {
+                *self.deferred.borrow_mut() = true;
+            }
+
+            /// Ends a batch started with [`Self::begin`], performing exactly one SPI write
+            /// and latch pulse for every write recorded since
+            pub fn commit(&self) -> Result<(), ()> {
+                *self.deferred.borrow_mut() = false;
+                self.flush()
+            }
+
+            /// Runs `f` with the register's decomposed pins in batch mode, then commits all
+            /// of its writes as a single SPI write and latch pulse
+            pub fn batch(&self, f: impl FnOnce(&mut [ShiftRegisterPin])) -> Result<(), ()> {
+                self.begin();
+                let mut pins = self.decompose();
+                f(&mut pins);
+                self.commit()
+            }
+
             /// Get embedded-hal output pins to control the shift register outputs
             pub fn decompose(&self) -> [ShiftRegisterPin; $size] {
-                // Create an uninitialized array of `MaybeUninit`. The `assume_init` is
-                // safe because the type we are claiming to have initialized here is a
-                // bunch of `MaybeUninit`s, which do not require initialization.
-                let mut pins: [MaybeUninit<ShiftRegisterPin>; $size] =
-                    unsafe { MaybeUninit::uninit().assume_init() };
-
-                // Dropping a `MaybeUninit` does nothing, so if there is a panic during this loop,
-                // we have a memory leak, but there is no memory safety issue.
-                for (index, elem) in pins.iter_mut().enumerate() {
-                    elem.write(ShiftRegisterPin::new(self, index));
-                }
-
-                // Everything is initialized. Transmute the array to the
-                // initialized type.
-                unsafe { mem::transmute::<_, [ShiftRegisterPin; $size]>(pins) }
+                core::array::from_fn(|index| ShiftRegisterPin::new(self, index))
             }
 
-            /// Consume the shift register and return the original clock, latch, and data output pins
-            pub fn release(self) -> (Pin1, Pin2, Pin3) {
+            /// Consume the shift register and return the original SPI peripheral and latch
+            /// output pin
+            pub fn release(self) -> (Spi, Pin2) {
                 let Self {
-                    clock,
+                    spi,
                     latch,
-                    data,
                     output_state: _,
                     inverted: _,
+                    shift_direction: _,
+                    deferred: _,
                 } = self;
-                (clock.into_inner(), latch.into_inner(), data.into_inner())
+                (spi.into_inner(), latch.into_inner())
             }
         }
     };
 }
 
-shift_register_builder!(ShiftRegister8, 8);
-shift_register_builder!(ShiftRegister16, 16);
-shift_register_builder!(ShiftRegister24, 24);
-shift_register_builder!(ShiftRegister32, 32);
-shift_register_builder!(ShiftRegister40, 40);
-shift_register_builder!(ShiftRegister48, 48);
-shift_register_builder!(ShiftRegister56, 56);
-shift_register_builder!(ShiftRegister64, 64);
-shift_register_builder!(ShiftRegister72, 72);
-shift_register_builder!(ShiftRegister80, 80);
-shift_register_builder!(ShiftRegister88, 88);
-shift_register_builder!(ShiftRegister96, 96);
-shift_register_builder!(ShiftRegister104, 104);
-shift_register_builder!(ShiftRegister112, 112);
-shift_register_builder!(ShiftRegister120, 120);
-shift_register_builder!(ShiftRegister128, 128);
+shift_register_spi_builder!(ShiftRegisterSpi8, 8);
+shift_register_spi_builder!(ShiftRegisterSpi16, 16);
+shift_register_spi_builder!(ShiftRegisterSpi24, 24);
+shift_register_spi_builder!(ShiftRegisterSpi32, 32);
+shift_register_spi_builder!(ShiftRegisterSpi40, 40);
+shift_register_spi_builder!(ShiftRegisterSpi48, 48);
+shift_register_spi_builder!(ShiftRegisterSpi56, 56);
+shift_register_spi_builder!(ShiftRegisterSpi64, 64);
+shift_register_spi_builder!(ShiftRegisterSpi72, 72);
+shift_register_spi_builder!(ShiftRegisterSpi80, 80);
+shift_register_spi_builder!(ShiftRegisterSpi88, 88);
+shift_register_spi_builder!(ShiftRegisterSpi96, 96);
+shift_register_spi_builder!(ShiftRegisterSpi104, 104);
+shift_register_spi_builder!(ShiftRegisterSpi112, 112);
+shift_register_spi_builder!(ShiftRegisterSpi120, 120);
+shift_register_spi_builder!(ShiftRegisterSpi128, 128);
 
-/// 8 output serial-in parallel-out shift register
-pub type ShiftRegister<Pin1, Pin2, Pin3> = ShiftRegister8<Pin1, Pin2, Pin3>;
+/// 8 output serial-in parallel-out shift register driven by hardware SPI
+pub type ShiftRegisterSpi<Spi, Pin2> = ShiftRegisterSpi8<Spi, Pin2>;
+
+/// Output pin of a [`SyncShiftRegister8`]
+///
+/// Unlike [`ShiftRegisterPin`], this holds a `Sync` trait object, so it can be shared
+/// between a main loop and an interrupt handler or across cores.
+pub struct SyncShiftRegisterPin<'a> {
+    shift_register: &'a (dyn ShiftRegisterInternal + Send + Sync),
+    index: usize,
+}
+
+impl<'a> SyncShiftRegisterPin<'a> {
+    fn new(shift_register: &'a (dyn ShiftRegisterInternal + Send + Sync), index: usize) -> Self {
+        SyncShiftRegisterPin {
+            shift_register,
+            index,
+        }
+    }
+}
+
+impl OutputPin for SyncShiftRegisterPin<'_> {
+    type Error = ();
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.shift_register.update(self.index, false)?;
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.shift_register.update(self.index, true)?;
+        Ok(())
+    }
+}
+
+struct SyncShiftRegisterState<Pin1, Pin2, Pin3> {
+    clock: Pin1,
+    latch: Pin2,
+    data: Pin3,
+    output_state: [bool; 8],
+    inverted: bool,
+    shift_direction: ShiftDirection,
+    deferred: bool,
+}
+
+/// `Sync` 8 output serial-in parallel-out shift register
+///
+/// Like [`ShiftRegister8`], but backed by a spin-based mutex instead of a `RefCell`, so the
+/// register and the pins returned by [`Self::decompose`] are `Send + Sync` and can be moved
+/// into another thread or shared across cores. The whole shift-out and latch sequence runs
+/// inside a single lock acquisition, so concurrent toggles from different contexts can't
+/// interleave clock edges.
+///
+/// `spin::Mutex` is a busy-wait spinlock, not a reentrancy-safe or interrupt-safe one: it
+/// does not disable interrupts while held. Sharing a register between a main loop and an
+/// interrupt handler on a *single* core is only sound if the main loop never holds the lock
+/// (i.e. is never inside [`Self::update`]/[`Self::batch`]) while that interrupt is enabled,
+/// otherwise an ISR that calls into the register while the main loop holds the guard spins
+/// forever. On multi-core targets this hazard doesn't apply, since the other core just spins
+/// until the lock is released.
+pub struct SyncShiftRegister8<Pin1, Pin2, Pin3>
+where
+    Pin1: OutputPin + Send,
+    Pin2: OutputPin + Send,
+    Pin3: OutputPin + Send,
+{
+    state: Mutex<SyncShiftRegisterState<Pin1, Pin2, Pin3>>,
+}
+
+impl<Pin1, Pin2, Pin3> ShiftRegisterInternal for SyncShiftRegister8<Pin1, Pin2, Pin3>
+where
+    Pin1: OutputPin + Send,
+    Pin2: OutputPin + Send,
+    Pin3: OutputPin + Send,
+{
+    /// Sets the value of the shift register output at `index` to value `command`
+    fn update(&self, index: usize, command: bool) -> Result<(), ()> {
+        let mut state = self.state.lock();
+        state.output_state[index] = command;
+        if state.deferred {
+            return Ok(());
+        }
+        Self::flush_locked(&mut state)
+    }
+}
+
+impl<Pin1, Pin2, Pin3> SyncShiftRegister8<Pin1, Pin2, Pin3>
+where
+    Pin1: OutputPin + Send,
+    Pin2: OutputPin + Send,
+    Pin3: OutputPin + Send,
+{
+    /// Shifts the current `output_state` out onto the data/clock pins and pulses the
+    /// latch, making it visible on the register outputs. Runs entirely under the caller's
+    /// lock guard, so the whole sequence is one atomic critical section.
+    fn flush_locked(state: &mut SyncShiftRegisterState<Pin1, Pin2, Pin3>) -> Result<(), ()> {
+        if state.inverted {
+            state.latch.set_high().map_err(|_e| ())?;
+        } else {
+            state.latch.set_low().map_err(|_e| ())?;
+        }
+
+        for i in 1..=state.output_state.len() {
+            let index = match state.shift_direction {
+                ShiftDirection::Msb => state.output_state.len() - i,
+                ShiftDirection::Lsb => i - 1,
+            };
+            if state.output_state[index] {
+                if state.inverted {
+                    state.data.set_low().map_err(|_e| ())?;
+                } else {
+                    state.data.set_high().map_err(|_e| ())?;
+                }
+            } else {
+                if state.inverted {
+                    state.data.set_high().map_err(|_e| ())?;
+                } else {
+                    state.data.set_low().map_err(|_e| ())?;
+                }
+            }
+            state.clock.set_high().map_err(|_e| ())?;
+            state.clock.set_low().map_err(|_e| ())?;
+        }
+
+        if state.inverted {
+            state.latch.set_low().map_err(|_e| ())?;
+        } else {
+            state.latch.set_high().map_err(|_e| ())?;
+        }
+        Ok(())
+    }
+
+    /// Creates a new SIPO shift register from clock, latch, and data output pins
+    pub fn new(clock: Pin1, latch: Pin2, data: Pin3) -> Self {
+        SyncShiftRegister8 {
+            state: Mutex::new(SyncShiftRegisterState {
+                clock,
+                latch,
+                data,
+                output_state: [false; 8],
+                inverted: false,
+                shift_direction: ShiftDirection::Msb,
+                deferred: false,
+            }),
+        }
+    }
+
+    /// Inverts the latch output pin. This depends on which shift register is used.
+    pub fn inverted(self, state: bool) -> Self {
+        self.state.lock().inverted = state;
+        self
+    }
+
+    /// Sets the order in which `output_state` bits are clocked out. Defaults to
+    /// [`ShiftDirection::Msb`], matching the previous fixed bit ordering.
+    pub fn shift_direction(self, direction: ShiftDirection) -> Self {
+        self.state.lock().shift_direction = direction;
+        self
+    }
+
+    /// Starts a batch of writes: until [`Self::commit`] is called, pin writes only update
+    /// the internal `output_state` buffer and do not touch the clock, latch, or data pins
+    pub fn begin(&self) {
+        self.state.lock().deferred = true;
+    }
+
+    /// Ends a batch started with [`Self::begin`], performing exactly one shift-out and
+    /// latch pulse for every write recorded since
+    pub fn commit(&self) -> Result<(), ()> {
+        let mut state = self.state.lock();
+        state.deferred = false;
+        Self::flush_locked(&mut state)
+    }
+
+    /// Runs `f` with the register's decomposed pins in batch mode, then commits all of its
+    /// writes as a single shift-out and latch pulse
+    pub fn batch(&self, f: impl FnOnce(&mut [SyncShiftRegisterPin])) -> Result<(), ()> {
+        self.begin();
+        let mut pins = self.decompose();
+        f(&mut pins);
+        self.commit()
+    }
+
+    /// Get embedded-hal output pins to control the shift register outputs
+    pub fn decompose(&self) -> [SyncShiftRegisterPin; 8] {
+        core::array::from_fn(|index| SyncShiftRegisterPin::new(self, index))
+    }
+
+    /// Consume the shift register and return the original clock, latch, and data output pins
+    pub fn release(self) -> (Pin1, Pin2, Pin3) {
+        let state = self.state.into_inner();
+        (state.clock, state.latch, state.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::rc::Rc;
+    use std::vec::Vec;
+
+    use super::*;
+
+    /// An `OutputPin` that records every level it is driven to, for asserting on the
+    /// exact sequence of pin writes a register produces
+    #[derive(Clone)]
+    struct MockPin {
+        log: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl MockPin {
+        fn new() -> (Self, Rc<RefCell<Vec<bool>>>) {
+            let log = Rc::new(RefCell::new(Vec::new()));
+            (MockPin { log: log.clone() }, log)
+        }
+    }
+
+    impl OutputPin for MockPin {
+        type Error = ();
+
+        fn set_low(&mut self) -> Result<(), Self::Error> {
+            self.log.borrow_mut().push(false);
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Self::Error> {
+            self.log.borrow_mut().push(true);
+            Ok(())
+        }
+    }
+
+    /// An SPI device that records every byte slice written to it
+    struct MockSpi {
+        log: Rc<RefCell<Vec<u8>>>,
+    }
+
+    impl MockSpi {
+        fn new() -> (Self, Rc<RefCell<Vec<u8>>>) {
+            let log = Rc::new(RefCell::new(Vec::new()));
+            (MockSpi { log: log.clone() }, log)
+        }
+    }
+
+    impl SpiWrite<u8> for MockSpi {
+        type Error = ();
+
+        fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+            self.log.borrow_mut().extend_from_slice(words);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn batch_performs_exactly_one_latch_pulse() {
+        let (clock, _clock_log) = MockPin::new();
+        let (latch, latch_log) = MockPin::new();
+        let (data, _data_log) = MockPin::new();
+        let register: ShiftRegister8<MockPin, MockPin, MockPin> =
+            ShiftRegister::new(clock, latch, data);
+
+        register
+            .batch(|pins| {
+                pins[0].set_high().unwrap();
+                pins[3].set_high().unwrap();
+                pins[7].set_low().unwrap();
+            })
+            .unwrap();
+
+        // One latch pulse (low, then high) no matter how many pins were written
+        assert_eq!(*latch_log.borrow(), [false, true]);
+    }
+
+    #[test]
+    fn msb_and_lsb_clock_out_opposite_bit_orders() {
+        let (clock, _clock_log) = MockPin::new();
+        let (latch, _latch_log) = MockPin::new();
+        let (data, data_log) = MockPin::new();
+        let mut register: ShiftRegister8<MockPin, MockPin, MockPin> =
+            ShiftRegister::new(clock, latch, data);
+
+        register.decompose()[0].set_high().unwrap();
+
+        // Msb (the default): bit 0 is clocked out last, so every earlier pulse is low
+        assert_eq!(
+            *data_log.borrow(),
+            [false, false, false, false, false, false, false, true]
+        );
+
+        data_log.borrow_mut().clear();
+        register = register.shift_direction(ShiftDirection::Lsb);
+        register.decompose()[0].set_high().unwrap();
+
+        // Lsb: bit 0 is clocked out first, so every later pulse is low
+        assert_eq!(
+            *data_log.borrow(),
+            [true, false, false, false, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn spi_output_matches_bitbanged_output() {
+        // Same write pattern on both backends should produce equivalent output: the byte
+        // the SPI variant writes should equal `output_state` packed bit-for-bit by index.
+        let pattern = [(1usize, true), (6usize, true)];
+        let expected_byte = pattern
+            .iter()
+            .fold(0u8, |byte, &(index, level)| {
+                if level { byte | (1 << index) } else { byte }
+            });
+
+        let write_pattern = |pins: &mut [ShiftRegisterPin]| {
+            for &(index, level) in pattern.iter() {
+                if level {
+                    pins[index].set_high().unwrap();
+                } else {
+                    pins[index].set_low().unwrap();
+                }
+            }
+        };
+
+        let (clock, _clock_log) = MockPin::new();
+        let (latch, _latch_log) = MockPin::new();
+        let (data, data_log) = MockPin::new();
+        let bitbang: ShiftRegister8<MockPin, MockPin, MockPin> =
+            ShiftRegister::new(clock, latch, data);
+        bitbang.batch(write_pattern).unwrap();
+
+        // Fold the clocked (Msb-ordered) pulse sequence into a byte and compare
+        let bitbang_byte = data_log.borrow().iter().enumerate().fold(
+            0u8,
+            |byte, (step, &bit)| {
+                if bit {
+                    byte | (1 << (7 - step))
+                } else {
+                    byte
+                }
+            },
+        );
+        assert_eq!(bitbang_byte, expected_byte);
+
+        let (spi, spi_log) = MockSpi::new();
+        let (latch, _latch_log) = MockPin::new();
+        let spi_register: ShiftRegisterSpi8<MockSpi, MockPin> =
+            ShiftRegisterSpi::new_spi(spi, latch);
+        spi_register.batch(write_pattern).unwrap();
+
+        assert_eq!(*spi_log.borrow(), [expected_byte]);
+    }
+}